@@ -1,13 +1,22 @@
 use color_eyre::eyre::{self, eyre, Result};
+use openssl::hash::MessageDigest;
+use openssl::pkey::{PKey, Private, Public};
+use openssl::sign::{Signer, Verifier};
 use openssl::{base64, sha::Sha256};
 use serde_jcs::to_vec as to_jcs;
 use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
+use std::time::SystemTime;
 use tokio::{fs, io::AsyncWriteExt};
 
 /// Computes the SHA-256 hash of the input, and encodes the result in Base64.
 /// '/' characters are replaced by '+'.
 ///
+/// Kept as a thin compatibility shim for callers written against the original
+/// addressing scheme. New code should go through [`mk_item`], which produces a
+/// self-describing multibase/multihash address (see [`HashAlgorithm`]).
+///
 /// # Example
 /// ```
 /// # use local_jcs_store::b64sha256;
@@ -23,12 +32,132 @@ pub fn b64sha256(bytes: &[u8]) -> String {
     return hash;
 }
 
-/// Stores many `Item`s in a directory.
+/// A content-addressing digest function.
+///
+/// Each variant maps onto a [multihash] code so that an address records *which*
+/// function produced it, letting the store grow beyond a single digest without
+/// breaking old addresses.
+///
+/// [multihash]: https://github.com/multiformats/multihash
+pub enum HashAlgorithm {
+    /// SHA-256, multihash code `0x12`, digest length `0x20`.
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// The multihash code identifying this function.
+    fn code(&self) -> u64 {
+        match self {
+            HashAlgorithm::Sha256 => 0x12,
+        }
+    }
+
+    /// Selects the algorithm for a multihash code, erroring on unknown codes.
+    fn from_code(code: u64) -> Result<Self> {
+        match code {
+            0x12 => Ok(HashAlgorithm::Sha256),
+            other => Err(eyre!("unknown multihash code {other:#x}")),
+        }
+    }
+
+    /// Computes the raw digest of `bytes`.
+    fn digest(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => {
+                let mut hash = Sha256::new();
+                hash.update(bytes);
+                hash.finish().to_vec()
+            }
+        }
+    }
+}
+
+/// A pluggable, content-addressed object store.
+///
+/// Implementors provide the four primitive operations — put, get, existence,
+/// and enumeration — over whatever backing medium they like; the `*_obj`
+/// helpers that (de)serialize JSON `Value`s are provided as default methods so
+/// every backend shares them. This crate ships a local-directory store
+/// ([`FsStore`]), an in-memory store ([`MemStore`]), and a read-only HTTP store
+/// ([`HttpStore`]).
+#[allow(async_fn_in_trait)]
+pub trait Store {
+    /// Saves an `Item`. Storing an item that already exists is a no-op.
+    async fn put_item(
+        &mut self,
+        item: &Item,
+    ) -> Result<()>;
+
+    /// Attempts to read an item, verifying its hash before returning it.
+    async fn get_item(
+        &self,
+        hash_b64: &str,
+    ) -> Result<Item>;
+
+    /// Reports whether an item with this address is present.
+    async fn has_item(
+        &self,
+        hash_b64: &str,
+    ) -> Result<bool>;
+
+    /// Lists the addresses of every item currently held.
+    async fn list_items(&self) -> Result<Vec<String>>;
+
+    /// Saves a JSON `Value` to the store. Does nothing if the item exists
+    async fn put_obj(
+        &mut self,
+        object: &Value,
+    ) -> Result<Item> {
+        let item: Item = mk_item(object, HashAlgorithm::Sha256)?;
+        self.put_item(&item).await?;
+
+        Ok(item)
+    }
+
+    /// Attempts to read an item & parse it into a JSON object
+    async fn get_obj(
+        &self,
+        hash_b64: &str,
+    ) -> Result<Value> {
+        use std::str::FromStr;
+
+        let item = self.get_item(hash_b64).await?;
+        Ok(Value::from_str(&item.json_utf8)?)
+    }
+
+    /// Stores a detached signature record under its own hash, returning it.
+    async fn put_signed(
+        &mut self,
+        signed: &SignedItem,
+    ) -> Result<Item> {
+        self.put_obj(&signed.to_value()).await
+    }
+
+    /// Loads a signature record and the object it references, verifies the
+    /// signature against the object's canonical bytes, and returns the object
+    /// together with the key that signed it.
+    ///
+    /// Because the stored bytes are already RFC 8785 JCS form, they are exactly
+    /// what was signed — no re-canonicalization is needed beyond the address
+    /// check performed while reading the object.
+    async fn verify_signed(
+        &self,
+        sig_hash: &str,
+    ) -> Result<(Item, PublicKey)> {
+        let signed = SignedItem::from_value(&self.get_obj(sig_hash).await?)?;
+        let item = self.get_item(&signed.object_hash).await?;
+        let public_key = signed.verify(&item)?;
+
+        Ok((item, public_key))
+    }
+}
+
+/// Stores many `Item`s in a directory, one file per item named by its address.
 ///
 /// # Example
 /// ```
 /// # use color_eyre::eyre::Result;
-/// # use local_jcs_store::Database;
+/// # use local_jcs_store::{Database, Store};
 /// #
 /// # #[tokio::main]
 /// # async fn main() {
@@ -41,26 +170,34 @@ pub fn b64sha256(bytes: &[u8]) -> String {
 /// # }
 /// ```
 
-pub struct Database {
+pub struct FsStore {
     path: PathBuf,
 }
 
+/// The local-directory store's original name, retained for source compatibility.
+pub type Database = FsStore;
+
 /// A JSON object and its hash.
 ///
 /// # Properties
 ///
 /// `json_utf8` - The cannonical JSON [JCS / RFC 8785] representation of an object
 ///
-/// `hash_b64` - The object's sha256 hash, encoded in base64, but with slashes replaced with pluses.
+/// `hash_b64` - The object's address: a [multihash] digest wrapped in [multibase]
+/// text. The leading character selects the text encoding (`'b'` lowercase base32,
+/// `'z'` base58btc) so the string is filesystem-safe and decodable back to the
+/// raw multihash bytes. The field name is retained for source compatibility.
 ///
 /// [rfc8785]: https://tools.ietf.org/html/rfc8785
+/// [multihash]: https://github.com/multiformats/multihash
+/// [multibase]: https://github.com/multiformats/multibase
 
 pub struct Item {
     pub hash_b64: String,
     pub json_utf8: String,
 }
 
-impl Database {
+impl FsStore {
     /// Opens a database, creating it if the path does not exist
     ///
     /// # Errors
@@ -82,8 +219,285 @@ impl Database {
         }
     }
 
-    /// Saves an `Item` to the database. Does nothing if the item exists
-    pub async fn put_item(
+    /// Reads an item from disk without ever buffering more than `max_bytes`.
+    ///
+    /// The file is streamed in fixed-size chunks; each chunk is folded into a
+    /// running digest and the read aborts the instant the accumulated length
+    /// exceeds `max_bytes`, so a corrupt or maliciously oversized file cannot
+    /// exhaust memory. Only once the stream ends and the digest matches the
+    /// requested address is the body materialized as UTF-8. This is the DoS-safe
+    /// path to use when reading from untrusted stores.
+    pub async fn get_item_bounded(
+        &self,
+        hash_b64: &str,
+        max_bytes: usize,
+    ) -> Result<Item> {
+        use tokio::io::AsyncReadExt;
+
+        let (algorithm, expected) = decode_multihash(&multibase_decode(hash_b64)?)?;
+        let mut hasher = match algorithm {
+            HashAlgorithm::Sha256 => Sha256::new(),
+        };
+
+        let mut file = fs::File::open(self.path.join(hash_b64)).await?;
+        let mut chunk = vec![0u8; 64 * 1024];
+        let mut body = Vec::new();
+
+        loop {
+            let read = file.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+
+            if body.len() + read > max_bytes {
+                return Err(eyre!("item exceeds the {max_bytes} byte limit"));
+            }
+
+            hasher.update(&chunk[..read]);
+            body.extend_from_slice(&chunk[..read]);
+        }
+
+        if hasher.finish().to_vec() != expected {
+            return Err(eyre!("Invalid Hash"));
+        }
+
+        Ok(Item {
+            hash_b64: hash_b64.to_string(),
+            json_utf8: String::from_utf8(body)?,
+        })
+    }
+
+    /// The directory holding pin markers, one empty file per pinned root.
+    fn pins_dir(&self) -> PathBuf {
+        self.path.join(".pins")
+    }
+
+    /// Pins a hash so it survives [`collect_garbage`](FsStore::collect_garbage).
+    pub async fn pin(
+        &self,
+        hash_b64: &str,
+    ) -> Result<()> {
+        let dir = self.pins_dir();
+        if !dir.try_exists()? {
+            fs::create_dir_all(&dir).await?;
+        }
+        fs::File::create(dir.join(hash_b64)).await?;
+
+        Ok(())
+    }
+
+    /// Removes a pin. Does nothing if the hash was not pinned.
+    pub async fn unpin(
+        &self,
+        hash_b64: &str,
+    ) -> Result<()> {
+        let path = self.pins_dir().join(hash_b64);
+        if path.try_exists()? {
+            fs::remove_file(path).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Lists every currently pinned hash.
+    async fn pinned(&self) -> Result<Vec<String>> {
+        let dir = self.pins_dir();
+        if !dir.try_exists()? {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(dir).await?;
+        let mut out = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if let Ok(name) = entry.file_name().into_string() {
+                out.push(name);
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Mark-and-sweep garbage collection over the object graph.
+    ///
+    /// Starting from `roots` and every pinned hash, each reachable item is loaded
+    /// and its outgoing [`links_of`] are enqueued, building the set of hashes the
+    /// graph can reach. Every top-level file whose name is not in that set is then
+    /// deleted. Files modified at or after the moment collection began (before
+    /// the mark phase) are left alone, so objects added concurrently are never
+    /// collected before they can be linked. Returns the hashes that were removed.
+    pub async fn collect_garbage(
+        &self,
+        roots: &[&str],
+    ) -> Result<Vec<String>> {
+        let started = SystemTime::now();
+
+        let mut marked: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<String> =
+            roots.iter().map(|r| r.to_string()).collect();
+        queue.extend(self.pinned().await?);
+
+        while let Some(hash) = queue.pop_front() {
+            if !marked.insert(hash.clone()) {
+                continue;
+            }
+
+            // An unreadable or non-JSON item simply contributes no links.
+            if let Ok(object) = self.get_obj(&hash).await {
+                queue.extend(links_of(&object));
+            }
+        }
+
+        let mut removed = Vec::new();
+        let mut entries = fs::read_dir(&self.path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+
+            let name = match entry.file_name().into_string() {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+
+            if marked.contains(&name) {
+                continue;
+            }
+
+            if let Ok(modified) = entry.metadata().await?.modified() {
+                if modified >= started {
+                    continue;
+                }
+            }
+
+            fs::remove_file(entry.path()).await?;
+            removed.push(name);
+        }
+
+        Ok(removed)
+    }
+
+    /// Audits every object in the store, and optionally repairs what it can.
+    ///
+    /// Each top-level file is re-read and its parsed value re-canonicalized. A
+    /// file is flagged when it does not parse as JSON, when it is not in
+    /// canonical JCS form, or when its name does not match the hash of its
+    /// canonical bytes. When `repair` is set, fixable files (everything but the
+    /// unparseable ones) are rewritten under their correct hash-named file and
+    /// the stale file is removed — useful for healing silent corruption or
+    /// migrating objects written by older or external tooling.
+    pub async fn fsck(
+        &self,
+        repair: bool,
+    ) -> Result<FsckReport> {
+        use std::str::FromStr;
+
+        let mut problems = Vec::new();
+        let mut entries = fs::read_dir(&self.path).await?;
+
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+
+            let path = entry.path();
+            let raw = fs::read(&path).await?;
+
+            let value = match std::str::from_utf8(&raw)
+                .ok()
+                .and_then(|text| Value::from_str(text).ok())
+            {
+                Some(value) => value,
+                None => {
+                    problems.push(FsckProblem {
+                        path,
+                        category: FsckCategory::InvalidJson,
+                        repaired: false,
+                    });
+                    continue;
+                }
+            };
+
+            let canonical = mk_item(&value, HashAlgorithm::Sha256)?;
+            let name = entry.file_name();
+
+            let non_canonical = raw.as_slice() != canonical.json_utf8.as_bytes();
+            let misnamed = name.to_str() != Some(canonical.hash_b64.as_str());
+            if !non_canonical && !misnamed {
+                continue;
+            }
+
+            let category = if non_canonical {
+                FsckCategory::NonCanonical
+            } else {
+                FsckCategory::HashMismatch
+            };
+
+            let mut repaired = false;
+            if repair {
+                let correct = self.path.join(&canonical.hash_b64);
+
+                // A HashMismatch means the bytes are already canonical, so when the
+                // correctly named file is already present this is just a duplicate
+                // and there is nothing to write. A NonCanonical file, however, must
+                // always be rewritten — even when it already sits under its correct
+                // hash name — or its stale bytes would stay on disk.
+                let duplicate = category == FsckCategory::HashMismatch && correct.try_exists()?;
+                if !duplicate {
+                    fs::File::create(&correct)
+                        .await?
+                        .write_all(canonical.json_utf8.as_bytes())
+                        .await?;
+                }
+                if path != correct {
+                    fs::remove_file(&path).await?;
+                }
+                repaired = true;
+            }
+
+            problems.push(FsckProblem {
+                path,
+                category,
+                repaired,
+            });
+        }
+
+        Ok(FsckReport { problems })
+    }
+}
+
+/// The kind of defect `fsck` found in a stored file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsckCategory {
+    /// The filename does not equal the hash of the file's canonical bytes.
+    HashMismatch,
+    /// The file's contents do not parse as JSON.
+    InvalidJson,
+    /// The file parses, but is not in canonical JCS form.
+    NonCanonical,
+}
+
+/// A single problem reported by [`FsStore::fsck`].
+#[derive(Debug)]
+pub struct FsckProblem {
+    pub path: PathBuf,
+    pub category: FsckCategory,
+    /// Whether a repair pass rewrote this file under its correct name.
+    pub repaired: bool,
+}
+
+/// The result of an [`FsStore::fsck`] run.
+#[derive(Debug)]
+pub struct FsckReport {
+    pub problems: Vec<FsckProblem>,
+}
+
+/// The default cap placed on a single item's size by the `get_item` convenience
+/// wrappers of [`FsStore`] and [`HttpStore`].
+const DEFAULT_MAX_ITEM_BYTES: usize = 16 * 1024 * 1024;
+
+impl Store for FsStore {
+    /// Saves an `Item` to disk. Does nothing if the item exists
+    async fn put_item(
         &mut self,
         item: &Item,
     ) -> Result<()> {
@@ -99,45 +513,182 @@ impl Database {
         Ok(())
     }
 
-    /// Saves a JSON `Value` to the database. Does nothing if the item exists
-    pub async fn put_obj(
+    /// Attempts to read an item from disk, capping its size at a sensible
+    /// default. Use [`FsStore::get_item_bounded`] to choose the cap yourself.
+    async fn get_item(
+        &self,
+        hash_b64: &str,
+    ) -> Result<Item> {
+        self.get_item_bounded(hash_b64, DEFAULT_MAX_ITEM_BYTES).await
+    }
+
+    async fn has_item(
+        &self,
+        hash_b64: &str,
+    ) -> Result<bool> {
+        Ok(self.path.join(hash_b64).try_exists()?)
+    }
+
+    async fn list_items(&self) -> Result<Vec<String>> {
+        let mut entries = fs::read_dir(&self.path).await?;
+        let mut out = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_file() {
+                if let Some(name) = entry.file_name().to_str() {
+                    out.push(name.to_string());
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// An in-memory [`Store`], handy for tests and as a local cache.
+#[derive(Default)]
+pub struct MemStore {
+    items: HashMap<String, String>,
+}
+
+impl MemStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Store for MemStore {
+    async fn put_item(
         &mut self,
-        object: &Value,
+        item: &Item,
+    ) -> Result<()> {
+        self.items
+            .entry(item.hash_b64.clone())
+            .or_insert_with(|| item.json_utf8.clone());
+
+        Ok(())
+    }
+
+    async fn get_item(
+        &self,
+        hash_b64: &str,
     ) -> Result<Item> {
-        let item: Item = mk_item(object)?;
-        self.put_item(&item).await?;
+        let json_utf8 = self
+            .items
+            .get(hash_b64)
+            .ok_or_else(|| eyre!("no item with hash {hash_b64}"))?
+            .clone();
+
+        let item = Item {
+            hash_b64: hash_b64.to_string(),
+            json_utf8,
+        };
+
+        item.check_hash()?;
 
         Ok(item)
     }
 
-    /// Attempts to read an item from disk
-    pub async fn get_item(
+    async fn has_item(
+        &self,
+        hash_b64: &str,
+    ) -> Result<bool> {
+        Ok(self.items.contains_key(hash_b64))
+    }
+
+    async fn list_items(&self) -> Result<Vec<String>> {
+        Ok(self.items.keys().cloned().collect())
+    }
+}
+
+/// A read-only [`Store`] that fetches items from an HTTP endpoint.
+///
+/// Items are retrieved with `GET {base_url}/{hash}`; the response body is still
+/// run through `check_hash` before it is trusted, so the remote is never assumed
+/// to be honest. Writes and enumeration are unsupported.
+pub struct HttpStore {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl HttpStore {
+    /// Creates a store that reads from `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn url(
+        &self,
+        hash_b64: &str,
+    ) -> String {
+        format!("{}/{}", self.base_url.trim_end_matches('/'), hash_b64)
+    }
+}
+
+impl Store for HttpStore {
+    async fn put_item(
+        &mut self,
+        _item: &Item,
+    ) -> Result<()> {
+        Err(eyre!("HttpStore is read-only"))
+    }
+
+    /// Fetches an item, streaming the response so an oversized or hostile
+    /// endpoint cannot exhaust memory.
+    ///
+    /// An advertised `Content-Length` over the cap is rejected before the body
+    /// is read, and the body is then accumulated chunk by chunk, aborting the
+    /// instant it crosses [`DEFAULT_MAX_ITEM_BYTES`]. Only once the whole body is
+    /// in hand is it run through `check_hash`, so the remote is never trusted.
+    async fn get_item(
         &self,
         hash_b64: &str,
     ) -> Result<Item> {
-        let path = self.path.join(hash_b64);
-        let json_utf8 = fs::read(path).await?;
-        let json_utf8 = String::from_utf8(json_utf8)?;
+        let mut response = self
+            .client
+            .get(self.url(hash_b64))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        if let Some(len) = response.content_length() {
+            if len > DEFAULT_MAX_ITEM_BYTES as u64 {
+                return Err(eyre!("item exceeds the {DEFAULT_MAX_ITEM_BYTES} byte limit"));
+            }
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if body.len() + chunk.len() > DEFAULT_MAX_ITEM_BYTES {
+                return Err(eyre!("item exceeds the {DEFAULT_MAX_ITEM_BYTES} byte limit"));
+            }
+            body.extend_from_slice(&chunk);
+        }
 
         let item = Item {
             hash_b64: hash_b64.to_string(),
-            json_utf8,
+            json_utf8: String::from_utf8(body)?,
         };
 
         item.check_hash()?;
 
-        return Ok(item);
+        Ok(item)
     }
 
-    /// Attempts to read an item from disk & parse it into a JSON object
-    pub async fn get_obj(
+    async fn has_item(
         &self,
         hash_b64: &str,
-    ) -> Result<Value> {
-        use std::str::FromStr;
+    ) -> Result<bool> {
+        let response = self.client.head(self.url(hash_b64)).send().await?;
+        Ok(response.status().is_success())
+    }
 
-        let item = self.get_item(hash_b64).await?;
-        Ok(Value::from_str(&item.json_utf8)?)
+    async fn list_items(&self) -> Result<Vec<String>> {
+        Err(eyre!("HttpStore does not support enumeration"))
     }
 }
 
@@ -156,14 +707,18 @@ impl core::fmt::Debug for Item {
 
 impl Item {
     /// Verify that the item has a valid hash.
-    ///  
+    ///
+    /// The address is decoded from multibase into its multihash bytes, which
+    /// selects the digest function to recompute over `json_utf8`.
+    ///
     /// # Returns
     /// - `Ok(&self)` if the hash is valid
     /// - `Err()` if the hash is **not** valid
     fn check_hash(&self) -> Result<&Self> {
-        let is_valid = self.hash_b64 == b64sha256(self.json_utf8.as_bytes());
+        let multihash = multibase_decode(&self.hash_b64)?;
+        let (algorithm, digest) = decode_multihash(&multihash)?;
 
-        if is_valid {
+        if algorithm.digest(self.json_utf8.as_bytes()) == digest {
             Ok(self)
         } else {
             Err(eyre!("Invalid Hash"))
@@ -175,7 +730,7 @@ impl TryFrom<Value> for Item {
     type Error = eyre::Error;
 
     fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
-        mk_item(&value)
+        mk_item(&value, HashAlgorithm::Sha256)
     }
 }
 
@@ -187,19 +742,23 @@ impl TryFrom<Item> for Value {
     type Error = eyre::Error;
 }
 
-/// Converts a JSON `Value` into an Item
+/// Converts a JSON `Value` into an Item, addressing it with `algorithm`.
 ///
 /// # Example
 /// ```
-/// # use local_jcs_store::mk_item;
+/// # use local_jcs_store::{mk_item, HashAlgorithm};
 /// use serde_json::json;
 /// let json = json!({"Hello":"World!"});
-/// let item = mk_item(&json).unwrap();
+/// let item = mk_item(&json, HashAlgorithm::Sha256).unwrap();
 /// println!("{:?}",item);
 /// ```
-pub fn mk_item(obj: &Value) -> Result<Item> {
+pub fn mk_item(
+    obj: &Value,
+    algorithm: HashAlgorithm,
+) -> Result<Item> {
     let json_utf8: Vec<u8> = to_jcs(&obj)?;
-    let hash_b64 = b64sha256(&json_utf8);
+    let digest = algorithm.digest(&json_utf8);
+    let hash_b64 = multibase_encode(&encode_multihash(&algorithm, &digest));
     let json_utf8: String = String::from_utf8(json_utf8)?;
 
     Ok(Item {
@@ -207,3 +766,566 @@ pub fn mk_item(obj: &Value) -> Result<Item> {
         hash_b64,
     })
 }
+
+/// Extracts the hashes a JSON object links to.
+///
+/// A string is treated as a link if it is a value under the reserved `"$link"`
+/// key, or if it parses on its own as a valid multibase/multihash address. This
+/// lets stored objects reference one another by hash and form immutable DAGs
+/// that [`FsStore::collect_garbage`] can walk.
+pub fn links_of(value: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    collect_links(value, &mut out);
+    out
+}
+
+fn collect_links(
+    value: &Value,
+    out: &mut Vec<String>,
+) {
+    match value {
+        Value::String(s) if looks_like_hash(s) => out.push(s.clone()),
+        Value::Array(items) => {
+            for item in items {
+                collect_links(item, out);
+            }
+        }
+        Value::Object(map) => {
+            for (key, child) in map {
+                if key == "$link" {
+                    if let Some(s) = child.as_str() {
+                        out.push(s.to_string());
+                        continue;
+                    }
+                }
+                collect_links(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether `s` decodes as a well-formed multibase/multihash address.
+fn looks_like_hash(s: &str) -> bool {
+    multibase_decode(s)
+        .and_then(|bytes| decode_multihash(&bytes))
+        .is_ok()
+}
+
+/// The signature scheme used to sign an object's canonical bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    /// EdDSA over Curve25519.
+    Ed25519,
+    /// RSASSA-PKCS1-v1_5 with SHA-256, as in JWS `RS256`.
+    Rs256,
+}
+
+impl SignatureAlgorithm {
+    /// The tag stored in the signature record.
+    fn tag(&self) -> &'static str {
+        match self {
+            SignatureAlgorithm::Ed25519 => "Ed25519",
+            SignatureAlgorithm::Rs256 => "RS256",
+        }
+    }
+
+    /// Parses a stored tag back into an algorithm.
+    fn from_tag(tag: &str) -> Result<Self> {
+        match tag {
+            "Ed25519" => Ok(SignatureAlgorithm::Ed25519),
+            "RS256" => Ok(SignatureAlgorithm::Rs256),
+            other => Err(eyre!("unknown signature algorithm '{other}'")),
+        }
+    }
+}
+
+/// A private key paired with the scheme it signs under.
+pub struct SigningKey {
+    pkey: PKey<Private>,
+    algorithm: SignatureAlgorithm,
+}
+
+impl SigningKey {
+    /// Wraps an OpenSSL private key for use with `algorithm`.
+    pub fn new(
+        pkey: PKey<Private>,
+        algorithm: SignatureAlgorithm,
+    ) -> Self {
+        Self { pkey, algorithm }
+    }
+
+    /// The matching public key, as it is stored in a signature record.
+    pub fn public_key(&self) -> Result<PublicKey> {
+        Ok(PublicKey {
+            der: self.pkey.public_key_to_der()?,
+            algorithm: self.algorithm,
+        })
+    }
+}
+
+/// A public key recovered from a signature record.
+pub struct PublicKey {
+    der: Vec<u8>,
+    algorithm: SignatureAlgorithm,
+}
+
+impl PublicKey {
+    /// The scheme this key verifies under.
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        self.algorithm
+    }
+
+    /// The key as a DER-encoded `SubjectPublicKeyInfo`.
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// The key as an OpenSSL [`PKey`].
+    pub fn pkey(&self) -> Result<PKey<Public>> {
+        Ok(PKey::public_key_from_der(&self.der)?)
+    }
+}
+
+/// A detached signature over an object's canonical JCS bytes.
+///
+/// The record names the signed object by hash, carries the raw signature and
+/// the signer's public key, and tags the scheme. It is itself stored as a small
+/// JCS object (see [`Store::put_signed`]), so a signature is content-addressed
+/// like everything else.
+pub struct SignedItem {
+    pub object_hash: String,
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub algorithm: SignatureAlgorithm,
+}
+
+impl SignedItem {
+    /// The JSON shape persisted to the store; byte fields are base64-encoded.
+    fn to_value(&self) -> Value {
+        serde_json::json!({
+            "algorithm": self.algorithm.tag(),
+            "object_hash": self.object_hash,
+            "public_key": base64::encode_block(&self.public_key),
+            "signature": base64::encode_block(&self.signature),
+        })
+    }
+
+    /// Parses a record previously produced by [`SignedItem::to_value`].
+    fn from_value(value: &Value) -> Result<Self> {
+        let field = |key: &str| -> Result<&str> {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| eyre!("signature record missing '{key}'"))
+        };
+
+        Ok(SignedItem {
+            algorithm: SignatureAlgorithm::from_tag(field("algorithm")?)?,
+            object_hash: field("object_hash")?.to_string(),
+            public_key: base64::decode_block(field("public_key")?)?,
+            signature: base64::decode_block(field("signature")?)?,
+        })
+    }
+
+    /// Verifies the signature against `item`'s canonical bytes.
+    ///
+    /// Returns the signer's [`PublicKey`] on success, or an error if the record
+    /// references a different object or the signature does not check out.
+    pub fn verify(
+        &self,
+        item: &Item,
+    ) -> Result<PublicKey> {
+        if self.object_hash != item.hash_b64 {
+            return Err(eyre!("signature does not reference this object"));
+        }
+
+        let pkey = PKey::public_key_from_der(&self.public_key)?;
+        let message = item.json_utf8.as_bytes();
+
+        let valid = match self.algorithm {
+            SignatureAlgorithm::Ed25519 => {
+                Verifier::new_without_digest(&pkey)?.verify_oneshot(&self.signature, message)?
+            }
+            SignatureAlgorithm::Rs256 => {
+                let mut verifier = Verifier::new(MessageDigest::sha256(), &pkey)?;
+                verifier.update(message)?;
+                verifier.verify(&self.signature)?
+            }
+        };
+
+        if valid {
+            Ok(PublicKey {
+                der: self.public_key.clone(),
+                algorithm: self.algorithm,
+            })
+        } else {
+            Err(eyre!("signature verification failed"))
+        }
+    }
+}
+
+/// Produces a detached signature over `item`'s canonical bytes.
+///
+/// The returned [`SignedItem`] can be stored with [`Store::put_signed`] and
+/// later checked with [`Store::verify_signed`].
+pub fn sign_item(
+    item: &Item,
+    key: &SigningKey,
+) -> Result<SignedItem> {
+    let message = item.json_utf8.as_bytes();
+
+    let signature = match key.algorithm {
+        SignatureAlgorithm::Ed25519 => {
+            Signer::new_without_digest(&key.pkey)?.sign_oneshot_to_vec(message)?
+        }
+        SignatureAlgorithm::Rs256 => {
+            let mut signer = Signer::new(MessageDigest::sha256(), &key.pkey)?;
+            signer.update(message)?;
+            signer.sign_to_vec()?
+        }
+    };
+
+    Ok(SignedItem {
+        object_hash: item.hash_b64.clone(),
+        signature,
+        public_key: key.pkey.public_key_to_der()?,
+        algorithm: key.algorithm,
+    })
+}
+
+/// Writes `n` as an unsigned LEB128 varint, the integer encoding multihash uses.
+fn write_varint(
+    mut n: u64,
+    out: &mut Vec<u8>,
+) {
+    loop {
+        let mut byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if n == 0 {
+            break;
+        }
+    }
+}
+
+/// Reads one unsigned LEB128 varint, returning its value and the bytes consumed.
+fn read_varint(bytes: &[u8]) -> Result<(u64, usize)> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((result, i + 1));
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(eyre!("varint too long"));
+        }
+    }
+    Err(eyre!("unexpected end of varint"))
+}
+
+/// Builds the multihash `varint(code) || varint(len) || digest`.
+fn encode_multihash(
+    algorithm: &HashAlgorithm,
+    digest: &[u8],
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_varint(algorithm.code(), &mut out);
+    write_varint(digest.len() as u64, &mut out);
+    out.extend_from_slice(digest);
+    out
+}
+
+/// Parses a multihash back into its algorithm and raw digest.
+fn decode_multihash(bytes: &[u8]) -> Result<(HashAlgorithm, Vec<u8>)> {
+    let (code, read) = read_varint(bytes)?;
+    let (len, read2) = read_varint(&bytes[read..])?;
+    let digest = &bytes[read + read2..];
+
+    if digest.len() != len as usize {
+        return Err(eyre!("multihash length mismatch"));
+    }
+
+    Ok((HashAlgorithm::from_code(code)?, digest.to_vec()))
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE58_ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Wraps multihash bytes in multibase, using lowercase base32 (`'b'`) by default.
+fn multibase_encode(bytes: &[u8]) -> String {
+    let mut out = String::from("b");
+    out.push_str(&base32_encode(bytes));
+    out
+}
+
+/// Decodes a multibase string into its raw bytes, dispatching on the prefix.
+fn multibase_decode(text: &str) -> Result<Vec<u8>> {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some('b') => base32_decode(chars.as_str()),
+        Some('z') => base58_decode(chars.as_str()),
+        Some(other) => Err(eyre!("unsupported multibase prefix '{other}'")),
+        None => Err(eyre!("empty multibase string")),
+    }
+}
+
+/// RFC 4648 lowercase base32, no padding.
+fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    out
+}
+
+/// Decodes RFC 4648 lowercase base32 without padding.
+fn base32_decode(text: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.chars() {
+        let value = match c {
+            'a'..='z' => c as u32 - 'a' as u32,
+            '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return Err(eyre!("invalid base32 character '{c}'")),
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+/// Decodes base58btc (as used by Bitcoin and IPFS) text into its raw bytes.
+fn base58_decode(text: &str) -> Result<Vec<u8>> {
+    let mut result: Vec<u8> = Vec::new();
+    for c in text.chars() {
+        let value = BASE58_ALPHABET
+            .iter()
+            .position(|&x| x == c as u8)
+            .ok_or_else(|| eyre!("invalid base58 character '{c}'"))? as u32;
+        let mut carry = value;
+        for byte in result.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            result.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = text.chars().take_while(|&c| c == '1').count();
+    result.extend(std::iter::repeat(0).take(zeros));
+    result.reverse();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::rsa::Rsa;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A unique, freshly-created directory under the system temp dir.
+    fn temp_dir(tag: &str) -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir()
+            .join(format!("jcs-test-{tag}-{}-{n}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn ed25519_key() -> SigningKey {
+        let pkey = PKey::generate_ed25519().unwrap();
+        SigningKey::new(pkey, SignatureAlgorithm::Ed25519)
+    }
+
+    fn rs256_key() -> SigningKey {
+        let rsa = Rsa::generate(2048).unwrap();
+        let pkey = PKey::from_rsa(rsa).unwrap();
+        SigningKey::new(pkey, SignatureAlgorithm::Rs256)
+    }
+
+    #[test]
+    fn multibase_multihash_round_trips() {
+        let digest = HashAlgorithm::Sha256.digest(b"payload");
+        let address = multibase_encode(&encode_multihash(&HashAlgorithm::Sha256, &digest));
+
+        let (algorithm, recovered) = decode_multihash(&multibase_decode(&address).unwrap()).unwrap();
+        assert!(matches!(algorithm, HashAlgorithm::Sha256));
+        assert_eq!(recovered, digest);
+        assert!(address.starts_with('b'));
+    }
+
+    #[test]
+    fn check_hash_accepts_valid_and_rejects_tampered() {
+        let item = mk_item(&json!({"a": 1, "b": 2}), HashAlgorithm::Sha256).unwrap();
+        assert!(item.check_hash().is_ok());
+
+        let tampered = Item {
+            hash_b64: item.hash_b64.clone(),
+            json_utf8: item.json_utf8.replace('1', "9"),
+        };
+        assert!(tampered.check_hash().is_err());
+    }
+
+    #[tokio::test]
+    async fn sign_item_round_trips_through_the_store() {
+        for key in [ed25519_key(), rs256_key()] {
+            let mut store = MemStore::new();
+            let item = store.put_obj(&json!({"claim": "hello"})).await.unwrap();
+
+            let signed = sign_item(&item, &key).unwrap();
+            let sig = store.put_signed(&signed).await.unwrap();
+
+            let (verified, public) = store.verify_signed(&sig.hash_b64).await.unwrap();
+            assert_eq!(verified.hash_b64, item.hash_b64);
+            assert_eq!(public.algorithm(), key.algorithm);
+        }
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_object() {
+        let key = ed25519_key();
+        let item = mk_item(&json!({"claim": "hello"}), HashAlgorithm::Sha256).unwrap();
+        let signed = sign_item(&item, &key).unwrap();
+
+        // A different object the signature does not cover.
+        let other = mk_item(&json!({"claim": "goodbye"}), HashAlgorithm::Sha256).unwrap();
+        assert!(signed.verify(&other).is_err());
+
+        // Same address but flipped bytes fails the signature check.
+        let forged = Item {
+            hash_b64: item.hash_b64.clone(),
+            json_utf8: item.json_utf8.replace("hello", "hella"),
+        };
+        assert!(signed.verify(&forged).is_err());
+    }
+
+    #[tokio::test]
+    async fn collect_garbage_keeps_reachable_and_pinned() {
+        let mut store = FsStore::open(temp_dir("gc")).unwrap();
+
+        let leaf = store.put_obj(&json!({"leaf": true})).await.unwrap();
+        let root = store
+            .put_obj(&json!({"child": {"$link": leaf.hash_b64}}))
+            .await
+            .unwrap();
+        let pinned = store.put_obj(&json!({"pinned": true})).await.unwrap();
+        let orphan = store.put_obj(&json!({"orphan": true})).await.unwrap();
+
+        store.pin(&pinned.hash_b64).await.unwrap();
+
+        let removed = store.collect_garbage(&[&root.hash_b64]).await.unwrap();
+
+        assert_eq!(removed, vec![orphan.hash_b64.clone()]);
+        assert!(store.has_item(&root.hash_b64).await.unwrap());
+        assert!(store.has_item(&leaf.hash_b64).await.unwrap());
+        assert!(store.has_item(&pinned.hash_b64).await.unwrap());
+        assert!(!store.has_item(&orphan.hash_b64).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn collect_garbage_spares_files_newer_than_the_sweep() {
+        let mut store = FsStore::open(temp_dir("gc-race")).unwrap();
+        let orphan = store.put_obj(&json!({"orphan": true})).await.unwrap();
+
+        // Simulate an item written by a concurrent writer after the sweep began
+        // by stamping it into the future; the guard must leave it untouched.
+        let future = SystemTime::now() + std::time::Duration::from_secs(3600);
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(store.path.join(&orphan.hash_b64))
+            .unwrap()
+            .set_modified(future)
+            .unwrap();
+
+        let removed = store.collect_garbage(&[]).await.unwrap();
+        assert!(removed.is_empty());
+        assert!(store.has_item(&orphan.hash_b64).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn fsck_reports_nothing_for_a_clean_store() {
+        let mut store = FsStore::open(temp_dir("fsck-clean")).unwrap();
+        store.put_obj(&json!({"a": 1, "b": 2})).await.unwrap();
+        store.pin("whatever").await.unwrap();
+
+        let report = store.fsck(false).await.unwrap();
+        assert!(report.problems.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fsck_repairs_non_canonical_file_under_its_correct_name() {
+        let dir = temp_dir("fsck-noncanon");
+        let store = FsStore::open(dir.clone()).unwrap();
+
+        // Canonical form reorders the keys, so these bytes are non-canonical yet
+        // already sit under the object's correct hash name.
+        let canonical = mk_item(&json!({"b": 1, "a": 2}), HashAlgorithm::Sha256).unwrap();
+        let path = dir.join(&canonical.hash_b64);
+        std::fs::write(&path, br#"{"b":1,"a":2}"#).unwrap();
+
+        let report = store.fsck(true).await.unwrap();
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(report.problems[0].category, FsckCategory::NonCanonical);
+        assert!(report.problems[0].repaired);
+
+        // The stale bytes must actually be gone, not merely claimed healed.
+        assert_eq!(std::fs::read(&path).unwrap(), canonical.json_utf8.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn fsck_repairs_a_misnamed_file() {
+        let dir = temp_dir("fsck-misnamed");
+        let store = FsStore::open(dir.clone()).unwrap();
+
+        let canonical = mk_item(&json!({"a": 2, "b": 1}), HashAlgorithm::Sha256).unwrap();
+        let wrong = dir.join("not-the-right-name");
+        std::fs::write(&wrong, canonical.json_utf8.as_bytes()).unwrap();
+
+        let report = store.fsck(true).await.unwrap();
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(report.problems[0].category, FsckCategory::HashMismatch);
+        assert!(report.problems[0].repaired);
+
+        assert!(!wrong.try_exists().unwrap());
+        assert!(dir.join(&canonical.hash_b64).try_exists().unwrap());
+    }
+
+    #[tokio::test]
+    async fn fsck_flags_unparseable_files_without_repairing() {
+        let dir = temp_dir("fsck-invalid");
+        let store = FsStore::open(dir.clone()).unwrap();
+        std::fs::write(dir.join("garbage"), b"not json").unwrap();
+
+        let report = store.fsck(true).await.unwrap();
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(report.problems[0].category, FsckCategory::InvalidJson);
+        assert!(!report.problems[0].repaired);
+    }
+}